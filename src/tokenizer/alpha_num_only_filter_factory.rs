@@ -0,0 +1,20 @@
+use tantivy::tokenizer::{AlphaNumOnlyFilter, BoxTokenFilter};
+
+#[derive(Clone)]
+pub struct AlphaNumOnlyFilterFactory {}
+
+impl AlphaNumOnlyFilterFactory {
+    pub fn new() -> Self {
+        AlphaNumOnlyFilterFactory {}
+    }
+
+    pub fn create(&self) -> BoxTokenFilter {
+        BoxTokenFilter::from(AlphaNumOnlyFilter)
+    }
+}
+
+impl Default for AlphaNumOnlyFilterFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,20 @@
+use tantivy::tokenizer::{AsciiFoldingFilter, BoxTokenFilter};
+
+#[derive(Clone)]
+pub struct AsciiFoldingFilterFactory {}
+
+impl AsciiFoldingFilterFactory {
+    pub fn new() -> Self {
+        AsciiFoldingFilterFactory {}
+    }
+
+    pub fn create(&self) -> BoxTokenFilter {
+        BoxTokenFilter::from(AsciiFoldingFilter)
+    }
+}
+
+impl Default for AsciiFoldingFilterFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
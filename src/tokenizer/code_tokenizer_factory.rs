@@ -0,0 +1,144 @@
+use tantivy::tokenizer::{BoxTokenizer, Token, TokenStream, Tokenizer};
+
+/// Splits identifiers into their word parts at camelCase, snake_case and
+/// digit boundaries, e.g. `parseHTTPResponse` -> `parse`, `HTTP`, `Response`.
+#[derive(Clone)]
+pub struct CodeTokenizer;
+
+impl Tokenizer for CodeTokenizer {
+    fn token_stream<'a>(&self, text: &'a str) -> Box<dyn TokenStream + 'a> {
+        Box::new(CodeTokenStream {
+            text,
+            offsets: sub_token_offsets(text),
+            index: 0,
+            token: Token::default(),
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum CharKind {
+    Lower,
+    Upper,
+    Digit,
+    Other,
+}
+
+fn char_kind(c: char) -> CharKind {
+    if c.is_ascii_digit() {
+        CharKind::Digit
+    } else if c.is_uppercase() {
+        CharKind::Upper
+    } else if c.is_alphanumeric() {
+        CharKind::Lower
+    } else {
+        CharKind::Other
+    }
+}
+
+/// Computes the `(offset_from, offset_to)` byte ranges of each sub-token,
+/// splitting alphanumeric runs at camelCase/acronym/digit boundaries and
+/// treating any other character as a delimiter.
+fn sub_token_offsets(text: &str) -> Vec<(usize, usize)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut offsets = Vec::new();
+
+    let mut word_start: Option<usize> = None;
+
+    let flush = |offsets: &mut Vec<(usize, usize)>, word_start: &mut Option<usize>, end: usize| {
+        if let Some(start) = word_start.take() {
+            offsets.push((start, end));
+        }
+    };
+
+    for i in 0..chars.len() {
+        let (idx, c) = chars[i];
+        let kind = char_kind(c);
+
+        if kind == CharKind::Other {
+            flush(&mut offsets, &mut word_start, idx);
+            continue;
+        }
+
+        if word_start.is_none() {
+            word_start = Some(idx);
+        } else {
+            let (_, prev_c) = chars[i - 1];
+            let prev_kind = char_kind(prev_c);
+
+            let boundary = match (prev_kind, kind) {
+                (CharKind::Digit, CharKind::Lower)
+                | (CharKind::Digit, CharKind::Upper)
+                | (CharKind::Lower, CharKind::Digit)
+                | (CharKind::Upper, CharKind::Digit) => true,
+                (CharKind::Lower, CharKind::Upper) => true,
+                (CharKind::Upper, CharKind::Upper) => {
+                    matches!(chars.get(i + 1), Some(&(_, next)) if char_kind(next) == CharKind::Lower)
+                }
+                _ => false,
+            };
+
+            if boundary {
+                flush(&mut offsets, &mut word_start, idx);
+                word_start = Some(idx);
+            }
+        }
+    }
+
+    flush(&mut offsets, &mut word_start, text.len());
+    offsets
+}
+
+struct CodeTokenStream<'a> {
+    text: &'a str,
+    offsets: Vec<(usize, usize)>,
+    index: usize,
+    token: Token,
+}
+
+impl<'a> TokenStream for CodeTokenStream<'a> {
+    fn advance(&mut self) -> bool {
+        if self.index >= self.offsets.len() {
+            return false;
+        }
+
+        let (start, end) = self.offsets[self.index];
+        self.index += 1;
+
+        self.token.text.clear();
+        self.token.text.push_str(&self.text[start..end]);
+        self.token.offset_from = start;
+        self.token.offset_to = end;
+        self.token.position = self.token.position.wrapping_add(1);
+        self.token.position_length = 1;
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+#[derive(Clone)]
+pub struct CodeTokenizerFactory {}
+
+impl CodeTokenizerFactory {
+    pub fn new() -> Self {
+        CodeTokenizerFactory {}
+    }
+
+    pub fn create(&self) -> BoxTokenizer {
+        BoxTokenizer::from(CodeTokenizer)
+    }
+}
+
+impl Default for CodeTokenizerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
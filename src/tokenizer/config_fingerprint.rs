@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+/// Recursively sorts JSON object keys (arrays keep their order) so that two
+/// semantically identical configs with different key ordering or whitespace
+/// serialize to the same bytes.
+fn canonicalize(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let sorted: BTreeMap<String, Value> =
+                map.iter().map(|(k, v)| (k.clone(), canonicalize(v))).collect();
+            Value::Object(sorted.into_iter().collect())
+        }
+        Value::Array(values) => Value::Array(values.iter().map(canonicalize).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Hashes a single analyzer's entire config (`tokenizer`, `filters`, and any
+/// sibling keys such as `detect_language`/`default_language`/`per_language`).
+/// The analyzer `name` is folded in explicitly on top of the canonicalized
+/// map so two analyzers with identical configs but different names hash
+/// differently. Arrays keep their order, so reordering `filters` changes the
+/// digest. Hashing the whole map rather than picking out individual keys means any
+/// field that affects how the analyzer is built — present now or added
+/// later — is covered without this function needing to know its name.
+pub fn config_hash(name: &str, analyzer_config_map: &Map<String, Value>) -> [u8; 32] {
+    let canonical_json =
+        serde_json::to_string(&canonicalize(&Value::Object(analyzer_config_map.clone()))).unwrap();
+
+    let mut hasher = Sha256::new();
+    hasher.update(name.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(canonical_json.as_bytes());
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map_from(json: &str) -> Map<String, Value> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_same_config_different_key_order_same_hash() {
+        let a = map_from(
+            r#"{"tokenizer": {"name": "simple"}, "filters": [{"name": "lower_case"}]}"#,
+        );
+        let b = map_from(
+            r#"{"filters": [{"name": "lower_case"}], "tokenizer": {"name": "simple"}}"#,
+        );
+
+        assert_eq!(config_hash("en_text", &a), config_hash("en_text", &b));
+    }
+
+    #[test]
+    fn test_different_args_different_hash() {
+        let a = map_from(
+            r#"{"tokenizer": {"name": "ngram", "args": {"min_gram": 2}}, "filters": []}"#,
+        );
+        let b = map_from(
+            r#"{"tokenizer": {"name": "ngram", "args": {"min_gram": 3}}, "filters": []}"#,
+        );
+
+        assert_ne!(config_hash("en_text", &a), config_hash("en_text", &b));
+    }
+
+    #[test]
+    fn test_different_name_different_hash() {
+        let config = map_from(r#"{"tokenizer": {"name": "simple"}, "filters": []}"#);
+
+        assert_ne!(
+            config_hash("en_text", &config),
+            config_hash("ja_text", &config)
+        );
+    }
+
+    #[test]
+    fn test_detect_language_sibling_keys_affect_hash() {
+        let a = map_from(
+            r#"{"tokenizer": {"name": "simple"}, "filters": [], "detect_language": true, "default_language": "english"}"#,
+        );
+        let b = map_from(
+            r#"{"tokenizer": {"name": "simple"}, "filters": [], "detect_language": true, "default_language": "french"}"#,
+        );
+
+        assert_ne!(config_hash("en_text", &a), config_hash("en_text", &b));
+    }
+}
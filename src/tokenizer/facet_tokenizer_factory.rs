@@ -0,0 +1,20 @@
+use tantivy::tokenizer::{BoxTokenizer, FacetTokenizer};
+
+#[derive(Clone)]
+pub struct FacetTokenizerFactory {}
+
+impl FacetTokenizerFactory {
+    pub fn new() -> Self {
+        FacetTokenizerFactory {}
+    }
+
+    pub fn create(&self) -> BoxTokenizer {
+        BoxTokenizer::from(FacetTokenizer)
+    }
+}
+
+impl Default for FacetTokenizerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use jieba_rs::Jieba;
+use serde::Deserialize;
+use tantivy::tokenizer::{BoxTokenizer, Token, TokenStream, Tokenizer};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CutMode {
+    Search,
+    All,
+    Exact,
+}
+
+impl Default for CutMode {
+    fn default() -> Self {
+        CutMode::Exact
+    }
+}
+
+#[derive(Deserialize)]
+struct JiebaTokenizerArgs {
+    #[serde(default)]
+    mode: CutMode,
+    #[serde(default = "default_hmm")]
+    hmm: bool,
+}
+
+impl Default for JiebaTokenizerArgs {
+    fn default() -> Self {
+        JiebaTokenizerArgs {
+            mode: CutMode::default(),
+            hmm: default_hmm(),
+        }
+    }
+}
+
+fn default_hmm() -> bool {
+    true
+}
+
+#[derive(Clone)]
+pub struct JiebaTokenizer {
+    jieba: Arc<Jieba>,
+    mode: Arc<CutModeConfig>,
+}
+
+struct CutModeConfig {
+    mode: CutMode,
+    hmm: bool,
+}
+
+impl Tokenizer for JiebaTokenizer {
+    fn token_stream<'a>(&self, text: &'a str) -> Box<dyn TokenStream + 'a> {
+        let segments = match self.mode.mode {
+            CutMode::Search => self.jieba.cut_for_search(text, self.mode.hmm),
+            CutMode::All => self.jieba.cut_all(text),
+            CutMode::Exact => self.jieba.cut(text, self.mode.hmm),
+        };
+
+        Box::new(JiebaTokenStream {
+            text,
+            segments,
+            segment_index: 0,
+            token: Token::default(),
+        })
+    }
+}
+
+struct JiebaTokenStream<'a> {
+    text: &'a str,
+    segments: Vec<&'a str>,
+    segment_index: usize,
+    token: Token,
+}
+
+impl<'a> TokenStream for JiebaTokenStream<'a> {
+    fn advance(&mut self) -> bool {
+        if self.segment_index >= self.segments.len() {
+            return false;
+        }
+
+        let segment = self.segments[self.segment_index];
+        self.segment_index += 1;
+
+        // `Jieba::cut`/`cut_all`/`cut_for_search` all return segments that are
+        // substrings of `text` (they slice rather than copy), so the byte
+        // offset of a segment within `text` is just pointer arithmetic. This
+        // holds for every mode, including `search`/`all`, whose segments can
+        // overlap and appear out of order — unlike scanning for the segment's
+        // content, which only works for `exact`'s non-overlapping, in-order
+        // output.
+        let offset_from = segment.as_ptr() as usize - self.text.as_ptr() as usize;
+        let offset_to = offset_from + segment.len();
+
+        self.token.text.clear();
+        self.token.text.push_str(segment);
+        self.token.offset_from = offset_from;
+        self.token.offset_to = offset_to;
+        self.token.position = self.token.position.wrapping_add(1);
+        self.token.position_length = 1;
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+#[derive(Clone)]
+pub struct JiebaTokenizerFactory {}
+
+impl JiebaTokenizerFactory {
+    pub fn new() -> Self {
+        JiebaTokenizerFactory {}
+    }
+
+    pub fn create(&self, json: &str) -> Result<BoxTokenizer, String> {
+        let args: JiebaTokenizerArgs = if json.is_empty() {
+            JiebaTokenizerArgs::default()
+        } else {
+            serde_json::from_str(json).map_err(|e| e.to_string())?
+        };
+
+        Ok(BoxTokenizer::from(JiebaTokenizer {
+            jieba: jieba().clone(),
+            mode: Arc::new(CutModeConfig {
+                mode: args.mode,
+                hmm: args.hmm,
+            }),
+        }))
+    }
+}
+
+impl Default for JiebaTokenizerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lazily builds the (sizeable) jieba dictionary once per process and shares
+/// it across every analyzer that registers a `cang_jie`/`jieba` tokenizer.
+fn jieba() -> &'static Arc<Jieba> {
+    use std::sync::OnceLock;
+    static JIEBA: OnceLock<Arc<Jieba>> = OnceLock::new();
+    JIEBA.get_or_init(|| Arc::new(Jieba::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(json: &str, text: &str) -> Vec<(String, usize, usize)> {
+        let factory = JiebaTokenizerFactory::new();
+        let tokenizer = factory.create(json).unwrap();
+        let mut stream = tokenizer.token_stream(text);
+        let mut out = Vec::new();
+        while stream.advance() {
+            let token = stream.token();
+            out.push((token.text.clone(), token.offset_from, token.offset_to));
+        }
+        out
+    }
+
+    fn assert_offsets_match_text(text: &str, tokens: &[(String, usize, usize)]) {
+        for (token_text, offset_from, offset_to) in tokens {
+            assert_eq!(&text[*offset_from..*offset_to], token_text);
+        }
+    }
+
+    #[test]
+    fn test_exact_mode_offsets() {
+        let text = "北京大学生前来应聘";
+        let out = tokens(r#"{"mode": "exact"}"#, text);
+        assert!(!out.is_empty());
+        assert_offsets_match_text(text, &out);
+    }
+
+    #[test]
+    fn test_search_mode_offsets_do_not_panic_on_overlap() {
+        let text = "北京大学生前来应聘";
+        let out = tokens(r#"{"mode": "search"}"#, text);
+        assert!(!out.is_empty());
+        assert_offsets_match_text(text, &out);
+    }
+
+    #[test]
+    fn test_all_mode_offsets_do_not_panic_on_overlap() {
+        let text = "北京大学生前来应聘";
+        let out = tokens(r#"{"mode": "all"}"#, text);
+        assert!(!out.is_empty());
+        assert_offsets_match_text(text, &out);
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tantivy::tokenizer::{
+    BoxTokenFilter, BoxTokenizer, LowerCaser, Stemmer, StopWordFilter, TextAnalyzer, TokenStream,
+    Tokenizer,
+};
+
+use crate::tokenizer::language_detector::detect_language;
+use crate::tokenizer::stemming_filter_factory::language_by_name;
+
+#[derive(Deserialize, Default)]
+struct PerLanguageArgs {
+    #[serde(default)]
+    stop_words: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct DetectLanguageArgs {
+    default_language: String,
+    #[serde(default)]
+    per_language: HashMap<String, PerLanguageArgs>,
+    #[serde(default = "default_confidence_threshold")]
+    confidence_threshold: f32,
+}
+
+fn default_confidence_threshold() -> f32 {
+    0.5
+}
+
+/// Wraps a base tokenizer and, per call to `token_stream`, detects the input
+/// text's language and dispatches to that language's stemmer + stop-word
+/// `TextAnalyzer` — so a single analyzer can index a mixed-language corpus
+/// without each document being pre-tagged.
+#[derive(Clone)]
+pub struct LanguageAwareTokenizer {
+    analyzers: Arc<HashMap<String, TextAnalyzer>>,
+    default_language: String,
+    confidence_threshold: f32,
+}
+
+impl Tokenizer for LanguageAwareTokenizer {
+    fn token_stream<'a>(&self, text: &'a str) -> Box<dyn TokenStream + 'a> {
+        let language = match detect_language(text) {
+            Some((language, confidence))
+                if confidence >= self.confidence_threshold && self.analyzers.contains_key(&language) =>
+            {
+                language
+            }
+            _ => self.default_language.clone(),
+        };
+
+        let analyzer = self
+            .analyzers
+            .get(&language)
+            .or_else(|| self.analyzers.get(&self.default_language))
+            .expect("default_language must have a registered analyzer");
+
+        analyzer.token_stream(text)
+    }
+}
+
+#[derive(Clone)]
+pub struct LanguageAwareTokenizerFactory {}
+
+impl LanguageAwareTokenizerFactory {
+    pub fn new() -> Self {
+        LanguageAwareTokenizerFactory {}
+    }
+
+    /// Builds a `detect_language` tokenizer on top of `base_tokenizer`, with
+    /// one `TextAnalyzer` (lower-case + stemmer + optional stop words) per
+    /// language named in `config_json`'s `"per_language"` map.
+    pub fn create(
+        &self,
+        base_tokenizer: BoxTokenizer,
+        config_json: &str,
+    ) -> Result<BoxTokenizer, String> {
+        let args: DetectLanguageArgs =
+            serde_json::from_str(config_json).map_err(|e| e.to_string())?;
+
+        let mut languages: Vec<String> = args.per_language.keys().cloned().collect();
+        if !languages.contains(&args.default_language) {
+            languages.push(args.default_language.clone());
+        }
+
+        let mut analyzers = HashMap::new();
+        for language in languages {
+            let per_language = args.per_language.get(&language);
+
+            let mut builder = TextAnalyzer::builder(base_tokenizer.clone())
+                .filter_dynamic(BoxTokenFilter::from(LowerCaser));
+
+            if let Some(stemmer_language) = language_by_name(&language) {
+                builder = builder.filter_dynamic(BoxTokenFilter::from(Stemmer::new(stemmer_language)));
+            }
+
+            if let Some(words) = per_language.map(|cfg| cfg.stop_words.clone()).filter(|w| !w.is_empty()) {
+                builder = builder.filter_dynamic(BoxTokenFilter::from(StopWordFilter::remove(words)));
+            }
+
+            analyzers.insert(language, builder.build());
+        }
+
+        Ok(BoxTokenizer::from(LanguageAwareTokenizer {
+            analyzers: Arc::new(analyzers),
+            default_language: args.default_language,
+            confidence_threshold: args.confidence_threshold,
+        }))
+    }
+}
+
+impl Default for LanguageAwareTokenizerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
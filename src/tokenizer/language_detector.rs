@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+/// Trigram frequency profiles for the Latin-script languages `detect_language`
+/// ships with out of the box. Each profile is a handful of the language's most
+/// distinctive trigrams, not an exhaustive corpus — good enough to arbitrate
+/// between a small configured set of `per_language` stemmers, not to replace a
+/// real language-id library.
+const TRIGRAM_PROFILES: &[(&str, &[&str])] = &[
+    (
+        "english",
+        &["the", "and", "ing", "ion", "tio", "ent", "for", "her", "ter", "hat"],
+    ),
+    (
+        "french",
+        &["les", "ent", "que", "tio", "des", "est", "ion", "dan", "ais", "eur"],
+    ),
+    (
+        "german",
+        &["der", "die", "und", "sch", "ein", "ich", "den", "cht", "end", "ver"],
+    ),
+    (
+        "spanish",
+        &["que", "los", "ent", "ion", "est", "par", "con", "del", "ado", "las"],
+    ),
+];
+
+const MIN_TRIGRAM_SCORE: f32 = 0.2;
+
+/// Identifies the dominant language of `text`, returning its lowercase name
+/// and a confidence in `[0, 1]`. Latin-script and Cyrillic results use the
+/// names [`StemmingFilterFactory`] accepts; CJK text is reported as
+/// `"chinese"`, which has no stemmer. Returns `None` when `text` has no
+/// alphabetic content at all, or when it is not CJK or Cyrillic and has fewer
+/// than three letters or no profile reaches `MIN_TRIGRAM_SCORE`.
+///
+/// Detection is purely heuristic: Unicode script ranges settle CJK and
+/// Cyrillic text outright (no Latin trigram table could help there), and a
+/// trigram-frequency match against [`TRIGRAM_PROFILES`] arbitrates between
+/// Latin-script languages.
+///
+/// [`StemmingFilterFactory`]: crate::tokenizer::stemming_filter_factory::StemmingFilterFactory
+pub fn detect_language(text: &str) -> Option<(String, f32)> {
+    let letters: Vec<char> = text.chars().filter(|c| c.is_alphabetic()).collect();
+    if letters.is_empty() {
+        return None;
+    }
+
+    if let Some(language) = detect_by_script(&letters) {
+        return Some((language, 1.0));
+    }
+
+    detect_by_trigram(&letters)
+}
+
+fn detect_by_script(letters: &[char]) -> Option<String> {
+    let mut cjk = 0usize;
+    let mut cyrillic = 0usize;
+
+    for &c in letters {
+        let code_point = c as u32;
+        if (0x4E00..=0x9FFF).contains(&code_point) // CJK Unified Ideographs
+            || (0x3040..=0x30FF).contains(&code_point) // Hiragana + Katakana
+            || (0xAC00..=0xD7A3).contains(&code_point)
+        // Hangul syllables
+        {
+            cjk += 1;
+        } else if (0x0400..=0x04FF).contains(&code_point) {
+            cyrillic += 1;
+        }
+    }
+
+    let total = letters.len();
+    if cjk * 2 > total {
+        Some("chinese".to_string())
+    } else if cyrillic * 2 > total {
+        Some("russian".to_string())
+    } else {
+        None
+    }
+}
+
+fn detect_by_trigram(letters: &[char]) -> Option<(String, f32)> {
+    if letters.len() < 3 {
+        return None;
+    }
+
+    let lower: Vec<char> = letters.iter().flat_map(|c| c.to_lowercase()).collect();
+    let mut trigrams: HashMap<String, usize> = HashMap::new();
+    for window in lower.windows(3) {
+        *trigrams.entry(window.iter().collect()).or_insert(0) += 1;
+    }
+
+    TRIGRAM_PROFILES
+        .iter()
+        .map(|(language, profile)| {
+            let hits = profile.iter().filter(|t| trigrams.contains_key(**t)).count();
+            (*language, hits as f32 / profile.len() as f32)
+        })
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .filter(|&(_, score)| score >= MIN_TRIGRAM_SCORE)
+        .map(|(language, score)| (language.to_string(), score))
+}
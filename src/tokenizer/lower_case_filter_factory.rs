@@ -0,0 +1,20 @@
+use tantivy::tokenizer::{BoxTokenFilter, LowerCaser};
+
+#[derive(Clone)]
+pub struct LowerCaseFilterFactory {}
+
+impl LowerCaseFilterFactory {
+    pub fn new() -> Self {
+        LowerCaseFilterFactory {}
+    }
+
+    pub fn create(&self) -> BoxTokenFilter {
+        BoxTokenFilter::from(LowerCaser)
+    }
+}
+
+impl Default for LowerCaseFilterFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
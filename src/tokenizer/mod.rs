@@ -0,0 +1,18 @@
+pub mod alpha_num_only_filter_factory;
+pub mod ascii_folding_filter_factory;
+pub mod code_tokenizer_factory;
+pub mod config_fingerprint;
+pub mod facet_tokenizer_factory;
+pub mod jieba_tokenizer_factory;
+pub mod language_aware_tokenizer_factory;
+pub mod language_detector;
+pub mod lower_case_filter_factory;
+pub mod ngram_tokenizer_factory;
+pub mod raw_tokenizer_factory;
+pub mod remove_long_filter_factory;
+pub mod simple_tokenizer_factory;
+pub mod split_compound_words_filter_factory;
+pub mod stemming_filter_factory;
+pub mod stop_word_filter_factory;
+pub mod tokenizer_config_error;
+pub mod tokenizer_initializer;
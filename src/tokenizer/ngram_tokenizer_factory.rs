@@ -0,0 +1,59 @@
+use serde::Deserialize;
+use tantivy::tokenizer::{BoxTokenizer, NgramTokenizer};
+
+#[derive(Deserialize)]
+struct NgramTokenizerArgs {
+    #[serde(default = "default_min_gram")]
+    min_gram: usize,
+    #[serde(default = "default_max_gram")]
+    max_gram: usize,
+    #[serde(default)]
+    prefix_only: bool,
+}
+
+impl Default for NgramTokenizerArgs {
+    fn default() -> Self {
+        NgramTokenizerArgs {
+            min_gram: default_min_gram(),
+            max_gram: default_max_gram(),
+            prefix_only: false,
+        }
+    }
+}
+
+fn default_min_gram() -> usize {
+    2
+}
+
+fn default_max_gram() -> usize {
+    3
+}
+
+#[derive(Clone)]
+pub struct NgramTokenizerFactory {}
+
+impl NgramTokenizerFactory {
+    pub fn new() -> Self {
+        NgramTokenizerFactory {}
+    }
+
+    pub fn create(&self, json: &str) -> Result<BoxTokenizer, String> {
+        let args: NgramTokenizerArgs = if json.is_empty() {
+            NgramTokenizerArgs::default()
+        } else {
+            serde_json::from_str(json).map_err(|e| e.to_string())?
+        };
+
+        Ok(BoxTokenizer::from(NgramTokenizer::new(
+            args.min_gram,
+            args.max_gram,
+            args.prefix_only,
+        )))
+    }
+}
+
+impl Default for NgramTokenizerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
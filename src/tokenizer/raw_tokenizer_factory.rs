@@ -0,0 +1,20 @@
+use tantivy::tokenizer::{BoxTokenizer, RawTokenizer};
+
+#[derive(Clone)]
+pub struct RawTokenizerFactory {}
+
+impl RawTokenizerFactory {
+    pub fn new() -> Self {
+        RawTokenizerFactory {}
+    }
+
+    pub fn create(&self) -> BoxTokenizer {
+        BoxTokenizer::from(RawTokenizer)
+    }
+}
+
+impl Default for RawTokenizerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
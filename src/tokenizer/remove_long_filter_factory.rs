@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use tantivy::tokenizer::{BoxTokenFilter, RemoveLongFilter};
+
+#[derive(Deserialize)]
+struct RemoveLongFilterArgs {
+    #[serde(default = "default_length_limit")]
+    length_limit: usize,
+}
+
+impl Default for RemoveLongFilterArgs {
+    fn default() -> Self {
+        RemoveLongFilterArgs {
+            length_limit: default_length_limit(),
+        }
+    }
+}
+
+fn default_length_limit() -> usize {
+    40
+}
+
+#[derive(Clone)]
+pub struct RemoveLongFilterFactory {}
+
+impl RemoveLongFilterFactory {
+    pub fn new() -> Self {
+        RemoveLongFilterFactory {}
+    }
+
+    pub fn create(&self, json: &str) -> Result<BoxTokenFilter, String> {
+        let args: RemoveLongFilterArgs = if json.is_empty() {
+            RemoveLongFilterArgs::default()
+        } else {
+            serde_json::from_str(json).map_err(|e| e.to_string())?
+        };
+
+        Ok(BoxTokenFilter::from(RemoveLongFilter::limit(
+            args.length_limit,
+        )))
+    }
+}
+
+impl Default for RemoveLongFilterFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
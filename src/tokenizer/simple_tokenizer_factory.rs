@@ -0,0 +1,20 @@
+use tantivy::tokenizer::{BoxTokenizer, SimpleTokenizer};
+
+#[derive(Clone)]
+pub struct SimpleTokenizerFactory {}
+
+impl SimpleTokenizerFactory {
+    pub fn new() -> Self {
+        SimpleTokenizerFactory {}
+    }
+
+    pub fn create(&self) -> BoxTokenizer {
+        BoxTokenizer::from(SimpleTokenizer)
+    }
+}
+
+impl Default for SimpleTokenizerFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
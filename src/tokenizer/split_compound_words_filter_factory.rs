@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tantivy::tokenizer::{BoxTokenFilter, Token, TokenFilter, TokenStream, Tokenizer};
+
+#[derive(Deserialize)]
+struct SplitCompoundWordsFilterArgs {
+    words: Vec<String>,
+    #[serde(default)]
+    include_original: bool,
+}
+
+/// Greedy, longest-match decompounder for Germanic-style compounds
+/// (`Schlüsselwort` -> `schlüssel`, `wort`). Emitted parts are lowercase.
+#[derive(Clone)]
+pub struct SplitCompoundWords {
+    dictionary: Arc<HashSet<String>>,
+    include_original: bool,
+}
+
+impl SplitCompoundWords {
+    /// Greedily splits `word` into dictionary-matched parts. Matching happens
+    /// against `word.to_lowercase()`, and parts are sliced from that same
+    /// lowercased string rather than `word` itself, since case-folding can
+    /// change a character's byte length (e.g. Turkish `İ`, some ligatures) —
+    /// slicing `word` at offsets computed over the lowercased string would
+    /// then land on the wrong byte, or not on a char boundary at all.
+    fn segment(&self, word: &str) -> Option<Vec<String>> {
+        let lower = word.to_lowercase();
+        let mut parts = Vec::new();
+        let mut offset = 0;
+
+        while offset < lower.len() {
+            let remainder = &lower[offset..];
+            let longest = (1..=remainder.chars().count())
+                .rev()
+                .map(|n| {
+                    let end: usize = remainder
+                        .char_indices()
+                        .nth(n)
+                        .map(|(i, _)| i)
+                        .unwrap_or(remainder.len());
+                    end
+                })
+                .find(|&end| self.dictionary.contains(&remainder[..end]));
+
+            match longest {
+                Some(end) => {
+                    parts.push(lower[offset..offset + end].to_string());
+                    offset += end;
+                }
+                None => return None,
+            }
+        }
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts)
+        }
+    }
+}
+
+impl TokenFilter for SplitCompoundWords {
+    type Tokenizer<T: Tokenizer> = SplitCompoundWordsFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> SplitCompoundWordsFilterWrapper<T> {
+        SplitCompoundWordsFilterWrapper {
+            inner: tokenizer,
+            filter: self,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SplitCompoundWordsFilterWrapper<T> {
+    inner: T,
+    filter: SplitCompoundWords,
+}
+
+impl<T: Tokenizer> Tokenizer for SplitCompoundWordsFilterWrapper<T> {
+    fn token_stream<'a>(&self, text: &'a str) -> Box<dyn TokenStream + 'a> {
+        let mut tokens = Vec::new();
+        {
+            let mut stream = self.inner.token_stream(text);
+            while stream.advance() {
+                let token = stream.token();
+                let base_offset = token.offset_from;
+
+                match self.filter.segment(&token.text) {
+                    Some(parts) if !parts.is_empty() => {
+                        if self.filter.include_original {
+                            tokens.push(token.clone());
+                        }
+
+                        let mut cursor = base_offset;
+                        for part in parts {
+                            let offset_from = cursor;
+                            let offset_to = offset_from + part.len();
+                            cursor = offset_to;
+
+                            tokens.push(Token {
+                                offset_from,
+                                offset_to,
+                                position: token.position,
+                                text: part,
+                                position_length: token.position_length,
+                            });
+                        }
+                    }
+                    _ => tokens.push(token.clone()),
+                }
+            }
+        }
+
+        Box::new(SplitCompoundWordsStream { tokens, index: 0 })
+    }
+}
+
+struct SplitCompoundWordsStream {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl TokenStream for SplitCompoundWordsStream {
+    fn advance(&mut self) -> bool {
+        if self.index >= self.tokens.len() {
+            return false;
+        }
+        self.index += 1;
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.index - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.index - 1]
+    }
+}
+
+#[derive(Clone)]
+pub struct SplitCompoundWordsFilterFactory {}
+
+impl SplitCompoundWordsFilterFactory {
+    pub fn new() -> Self {
+        SplitCompoundWordsFilterFactory {}
+    }
+
+    pub fn create(&self, json: &str) -> Result<BoxTokenFilter, String> {
+        let args: SplitCompoundWordsFilterArgs =
+            serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let dictionary: HashSet<String> = args.words.into_iter().map(|w| w.to_lowercase()).collect();
+
+        Ok(BoxTokenFilter::from(SplitCompoundWords {
+            dictionary: Arc::new(dictionary),
+            include_original: args.include_original,
+        }))
+    }
+}
+
+impl Default for SplitCompoundWordsFilterFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
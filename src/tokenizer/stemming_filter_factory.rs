@@ -0,0 +1,59 @@
+use serde::Deserialize;
+use tantivy::tokenizer::{BoxTokenFilter, Language, Stemmer};
+
+#[derive(Deserialize)]
+struct StemmingFilterArgs {
+    stemmer_algorithm: String,
+}
+
+/// Maps the lowercase language names used in tokenizer config (and by
+/// `detect_language`'s language classifier) onto tantivy's `Stemmer`
+/// `Language` variants.
+pub(crate) fn language_by_name(name: &str) -> Option<Language> {
+    let language = match name {
+        "arabic" => Language::Arabic,
+        "danish" => Language::Danish,
+        "dutch" => Language::Dutch,
+        "english" => Language::English,
+        "finnish" => Language::Finnish,
+        "french" => Language::French,
+        "german" => Language::German,
+        "greek" => Language::Greek,
+        "hungarian" => Language::Hungarian,
+        "italian" => Language::Italian,
+        "norwegian" => Language::Norwegian,
+        "portuguese" => Language::Portuguese,
+        "romanian" => Language::Romanian,
+        "russian" => Language::Russian,
+        "spanish" => Language::Spanish,
+        "swedish" => Language::Swedish,
+        "tamil" => Language::Tamil,
+        "turkish" => Language::Turkish,
+        _ => return None,
+    };
+    Some(language)
+}
+
+#[derive(Clone)]
+pub struct StemmingFilterFactory {}
+
+impl StemmingFilterFactory {
+    pub fn new() -> Self {
+        StemmingFilterFactory {}
+    }
+
+    pub fn create(&self, json: &str) -> Result<BoxTokenFilter, String> {
+        let args: StemmingFilterArgs = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let language = language_by_name(&args.stemmer_algorithm).ok_or_else(|| {
+            format!("unknown stemmer algorithm: {}", args.stemmer_algorithm)
+        })?;
+
+        Ok(BoxTokenFilter::from(Stemmer::new(language)))
+    }
+}
+
+impl Default for StemmingFilterFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
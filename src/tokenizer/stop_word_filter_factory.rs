@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use tantivy::tokenizer::{BoxTokenFilter, StopWordFilter};
+
+#[derive(Deserialize)]
+struct StopWordFilterArgs {
+    words: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct StopWordFilterFactory {}
+
+impl StopWordFilterFactory {
+    pub fn new() -> Self {
+        StopWordFilterFactory {}
+    }
+
+    pub fn create(&self, json: &str) -> Result<BoxTokenFilter, String> {
+        let args: StopWordFilterArgs = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        Ok(BoxTokenFilter::from(StopWordFilter::remove(args.words)))
+    }
+}
+
+impl Default for StopWordFilterFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+
+/// Everything that can go wrong while turning a tokenizer-config JSON
+/// document into registered analyzers. `MissingField` carries a JSON pointer
+/// path that starts with the analyzer name. `UnknownTokenizer`,
+/// `UnknownFilter` and `InvalidArgs` carry only the tokenizer or filter name,
+/// not the analyzer it belongs to, and `InvalidJson` carries only the parse
+/// error.
+#[derive(Debug)]
+pub enum TokenizerConfigError {
+    /// The top-level config string was not valid JSON.
+    InvalidJson(serde_json::Error),
+    /// A required field was missing or was not of the expected JSON type.
+    MissingField { path: String },
+    /// `tokenizer.name` named a tokenizer this build doesn't register.
+    UnknownTokenizer { name: String },
+    /// A `filters[].name` named a filter this build doesn't register.
+    UnknownFilter { name: String },
+    /// A tokenizer or filter's `args` failed to parse or were rejected for
+    /// that kind.
+    InvalidArgs { name: String, reason: String },
+}
+
+impl fmt::Display for TokenizerConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenizerConfigError::InvalidJson(err) => {
+                write!(f, "invalid tokenizer config JSON: {}", err)
+            }
+            TokenizerConfigError::MissingField { path } => {
+                write!(f, "missing or invalid field at {}", path)
+            }
+            TokenizerConfigError::UnknownTokenizer { name } => {
+                write!(f, "unknown tokenizer: {}", name)
+            }
+            TokenizerConfigError::UnknownFilter { name } => {
+                write!(f, "unknown filter: {}", name)
+            }
+            TokenizerConfigError::InvalidArgs { name, reason } => {
+                write!(f, "invalid args for {}: {}", name, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TokenizerConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TokenizerConfigError::InvalidJson(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -1,20 +1,31 @@
+use std::collections::HashMap;
+
 use log::*;
 use serde_json::Value;
-use tantivy::tokenizer::TokenizerManager;
+use tantivy::tokenizer::{BoxTokenFilter, BoxTokenizer, TextAnalyzer, TokenizerManager};
 
 use crate::tokenizer::alpha_num_only_filter_factory::AlphaNumOnlyFilterFactory;
 use crate::tokenizer::ascii_folding_filter_factory::AsciiFoldingFilterFactory;
+use crate::tokenizer::code_tokenizer_factory::CodeTokenizerFactory;
+use crate::tokenizer::config_fingerprint;
 use crate::tokenizer::facet_tokenizer_factory::FacetTokenizerFactory;
+use crate::tokenizer::jieba_tokenizer_factory::JiebaTokenizerFactory;
+use crate::tokenizer::language_aware_tokenizer_factory::LanguageAwareTokenizerFactory;
 use crate::tokenizer::lower_case_filter_factory::LowerCaseFilterFactory;
 use crate::tokenizer::ngram_tokenizer_factory::NgramTokenizerFactory;
 use crate::tokenizer::raw_tokenizer_factory::RawTokenizerFactory;
 use crate::tokenizer::remove_long_filter_factory::RemoveLongFilterFactory;
 use crate::tokenizer::simple_tokenizer_factory::SimpleTokenizerFactory;
+use crate::tokenizer::split_compound_words_filter_factory::SplitCompoundWordsFilterFactory;
 use crate::tokenizer::stemming_filter_factory::StemmingFilterFactory;
 use crate::tokenizer::stop_word_filter_factory::StopWordFilterFactory;
+use crate::tokenizer::tokenizer_config_error::TokenizerConfigError;
 
 pub struct TokenizerInitializer {
+    code_tokenizer_factory: CodeTokenizerFactory,
     facet_tokenizer_factory: FacetTokenizerFactory,
+    jieba_tokenizer_factory: JiebaTokenizerFactory,
+    language_aware_tokenizer_factory: LanguageAwareTokenizerFactory,
     ngram_tokenizer_factory: NgramTokenizerFactory,
     raw_tokenizer_factory: RawTokenizerFactory,
     simple_tokenizer_factory: SimpleTokenizerFactory,
@@ -23,14 +34,23 @@ pub struct TokenizerInitializer {
     ascii_folding_filter_factory: AsciiFoldingFilterFactory,
     lower_case_filter_factory: LowerCaseFilterFactory,
     remove_long_filter_factory: RemoveLongFilterFactory,
+    split_compound_words_filter_factory: SplitCompoundWordsFilterFactory,
     stemming_filter_factory: StemmingFilterFactory,
     stop_word_filter_factory: StopWordFilterFactory,
+
+    /// `config_hash` of the config each already-registered analyzer was last
+    /// built from, so `init` can skip rebuilding one whose config is
+    /// unchanged.
+    registered_config_hashes: HashMap<String, [u8; 32]>,
 }
 
 impl TokenizerInitializer {
     pub fn new() -> Self {
         TokenizerInitializer {
+            code_tokenizer_factory: CodeTokenizerFactory::new(),
             facet_tokenizer_factory: FacetTokenizerFactory::new(),
+            jieba_tokenizer_factory: JiebaTokenizerFactory::new(),
+            language_aware_tokenizer_factory: LanguageAwareTokenizerFactory::new(),
             ngram_tokenizer_factory: NgramTokenizerFactory::new(),
             raw_tokenizer_factory: RawTokenizerFactory::new(),
             simple_tokenizer_factory: SimpleTokenizerFactory::new(),
@@ -39,25 +59,80 @@ impl TokenizerInitializer {
             ascii_folding_filter_factory: AsciiFoldingFilterFactory::new(),
             lower_case_filter_factory: LowerCaseFilterFactory::new(),
             remove_long_filter_factory: RemoveLongFilterFactory::new(),
+            split_compound_words_filter_factory: SplitCompoundWordsFilterFactory::new(),
             stemming_filter_factory: StemmingFilterFactory::new(),
             stop_word_filter_factory: StopWordFilterFactory::new(),
+
+            registered_config_hashes: HashMap::new(),
         }
     }
 
-    pub fn init(&mut self, manager: &TokenizerManager, config: &str) {
-        let config_value: Value = serde_json::from_str(config).unwrap();
+    /// Hashes analyzer `name`'s config within the full tokenizer `config`
+    /// JSON. Two configs that differ only in object key ordering or
+    /// whitespace hash identically; any change to the analyzer's tokenizer,
+    /// filters, or language-detection settings changes the hash. Useful for
+    /// callers that want to detect whether a persisted index was built with
+    /// a tokenizer definition that has since diverged from the live config.
+    pub fn config_hash(&self, name: &str, config: &str) -> Result<[u8; 32], TokenizerConfigError> {
+        let config_value: Value =
+            serde_json::from_str(config).map_err(TokenizerConfigError::InvalidJson)?;
+        let tokenizer_config_map = config_value
+            .get(name)
+            .and_then(Value::as_object)
+            .ok_or_else(|| TokenizerConfigError::MissingField {
+                path: format!("/{}", name),
+            })?;
+
+        Ok(config_fingerprint::config_hash(name, tokenizer_config_map))
+    }
+
+    pub fn init(&mut self, manager: &TokenizerManager, config: &str) -> Result<(), TokenizerConfigError> {
+        let config_value: Value =
+            serde_json::from_str(config).map_err(TokenizerConfigError::InvalidJson)?;
+
+        let config_map = config_value
+            .as_object()
+            .ok_or_else(|| TokenizerConfigError::MissingField {
+                path: "/".to_string(),
+            })?;
 
-        let config_map = config_value.as_object().unwrap();
         for (name, tokenizer_config_value) in config_map {
             debug!("name: {}", name);
 
-            let tokenizer_config_map = tokenizer_config_value.as_object().unwrap();
+            let tokenizer_config_map =
+                tokenizer_config_value
+                    .as_object()
+                    .ok_or_else(|| TokenizerConfigError::MissingField {
+                        path: format!("/{}", name),
+                    })?;
+
+            // Skip analyzers whose config is unchanged since the last call to
+            // `init` registered them. The hash alone isn't enough: if this
+            // initializer is reused against a different (e.g. freshly
+            // created) `manager` that doesn't hold the analyzer yet, it must
+            // still be registered even though the hash matches what we
+            // registered into a previous manager.
+            let config_hash = config_fingerprint::config_hash(name, tokenizer_config_map);
+            if manager.get(name).is_some() && self.registered_config_hashes.get(name) == Some(&config_hash) {
+                debug!("{} is unchanged, skipping re-registration", name);
+                continue;
+            }
 
             // tokenizer
-            let tokenizer_settings = tokenizer_config_map["tokenizer"].as_object().unwrap();
+            let tokenizer_settings = tokenizer_config_map
+                .get("tokenizer")
+                .and_then(Value::as_object)
+                .ok_or_else(|| TokenizerConfigError::MissingField {
+                    path: format!("/{}/tokenizer", name),
+                })?;
             debug!("tokenizer_setting: {:?}", tokenizer_settings);
 
-            let tokenizer_name = tokenizer_settings["name"].as_str().unwrap();
+            let tokenizer_name = tokenizer_settings
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| TokenizerConfigError::MissingField {
+                    path: format!("/{}/tokenizer/name", name),
+                })?;
             debug!("tokenizer_name: {:?}", tokenizer_name);
 
             let mut tokenizer_args = String::new();
@@ -67,15 +142,28 @@ impl TokenizerInitializer {
             debug!("tokenizer_args: {:?}", tokenizer_args);
 
             // filters
-            // create vector for storing filters
-            //let mut filters: Vec<_> = Vec::new();
+            // create vector for storing filters, in declared order
+            let mut filters: Vec<BoxTokenFilter> = Vec::new();
             if tokenizer_config_map.contains_key("filters") {
-                let filters_config_value = tokenizer_config_map["filters"].as_array().unwrap();
-                for filter_config_value in filters_config_value {
-                    let filter_settings = filter_config_value.as_object().unwrap();
+                let filters_config_value = tokenizer_config_map
+                    .get("filters")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| TokenizerConfigError::MissingField {
+                        path: format!("/{}/filters", name),
+                    })?;
+                for (i, filter_config_value) in filters_config_value.iter().enumerate() {
+                    let filter_settings = filter_config_value.as_object().ok_or_else(|| {
+                        TokenizerConfigError::MissingField {
+                            path: format!("/{}/filters/{}", name, i),
+                        }
+                    })?;
                     debug!("filter_settings: {:?}", filter_settings);
 
-                    let filter_name = filter_settings["name"].as_str().unwrap();
+                    let filter_name = filter_settings.get("name").and_then(Value::as_str).ok_or_else(
+                        || TokenizerConfigError::MissingField {
+                            path: format!("/{}/filters/{}/name", name, i),
+                        },
+                    )?;
                     debug!("filter_name: {:?}", filter_name);
 
                     let mut filter_args = String::new();
@@ -85,95 +173,117 @@ impl TokenizerInitializer {
                     debug!("filter_args: {:?}", filter_args);
 
                     // create filter
-                    match filter_name {
-                        "alpha_num_only" => {
-                            let _filter = self.alpha_num_only_filter_factory.clone().create();
-                            // push created filter to vector
-                            //filters.push(_filter);
-                        }
-                        "ascii_folding" => {
-                            let _filter = self.ascii_folding_filter_factory.clone().create();
-                            // push created filter to vector
-                            //filters.push(_filter);
-                        }
-                        "lower_case" => {
-                            let _filter = self.lower_case_filter_factory.clone().create();
-                        }
-                        "remove_long" => {
-                            let _filter = self
-                                .remove_long_filter_factory
-                                .clone()
-                                .create(filter_args.as_ref());
-                            // push created filter to vector
-                            //filters.push(_filter);
-                        }
-                        "stemming" => {
-                            let _filter = self
-                                .stemming_filter_factory
-                                .clone()
-                                .create(filter_args.as_ref());
-                            // push created filter to vector
-                            //filters.push(_filter);
-                        }
-                        "stop_word" => {
-                            let _filter = self
-                                .stop_word_filter_factory
-                                .clone()
-                                .create(filter_args.as_ref());
-                            // push created filter to vector
-                            //filters.push(_filter);
-                        }
+                    let filter = match filter_name {
+                        "alpha_num_only" => self.alpha_num_only_filter_factory.clone().create(),
+                        "ascii_folding" => self.ascii_folding_filter_factory.clone().create(),
+                        "lower_case" => self.lower_case_filter_factory.clone().create(),
+                        "remove_long" => self
+                            .remove_long_filter_factory
+                            .clone()
+                            .create(filter_args.as_ref())
+                            .map_err(|reason| TokenizerConfigError::InvalidArgs {
+                                name: filter_name.to_string(),
+                                reason,
+                            })?,
+                        "split_compound_words" => self
+                            .split_compound_words_filter_factory
+                            .clone()
+                            .create(filter_args.as_ref())
+                            .map_err(|reason| TokenizerConfigError::InvalidArgs {
+                                name: filter_name.to_string(),
+                                reason,
+                            })?,
+                        "stemming" => self
+                            .stemming_filter_factory
+                            .clone()
+                            .create(filter_args.as_ref())
+                            .map_err(|reason| TokenizerConfigError::InvalidArgs {
+                                name: filter_name.to_string(),
+                                reason,
+                            })?,
+                        "stop_word" => self
+                            .stop_word_filter_factory
+                            .clone()
+                            .create(filter_args.as_ref())
+                            .map_err(|reason| TokenizerConfigError::InvalidArgs {
+                                name: filter_name.to_string(),
+                                reason,
+                            })?,
                         _ => {
-                            panic!("unknown filter: {}", filter_name);
+                            return Err(TokenizerConfigError::UnknownFilter {
+                                name: filter_name.to_string(),
+                            })
                         }
-                    }
+                    };
+                    // push created filter to vector
+                    filters.push(filter);
                 }
             }
 
             // create tokenizer
-            match tokenizer_name {
-                "facet" => {
-                    let tokenizer = self.facet_tokenizer_factory.clone().create();
-                    // add filters to tokenizer
-                    //for filter in filters.iter() {
-                    //    tokenizer.filter(filter);
-                    //}
-                    manager.register(name, tokenizer)
-                }
-                "ngram" => {
-                    let tokenizer = self
-                        .ngram_tokenizer_factory
-                        .clone()
-                        .create(tokenizer_args.as_ref());
-                    // add filters to tokenizer
-                    //for filter in filters.iter() {
-                    //    tokenizer.filter(filter);
-                    //}
-                    manager.register(name, tokenizer)
-                }
-                "raw" => {
-                    let tokenizer = self.raw_tokenizer_factory.clone().create();
-                    // add filters to tokenizer
-                    //for filter in filters.iter() {
-                    //    tokenizer.filter(filter);
-                    //}
-                    manager.register(name, tokenizer)
-                }
-                "simple" => {
-                    let tokenizer = self.simple_tokenizer_factory.clone().create();
-                    // add filters to tokenizer
-                    //for filter in filters.iter() {
-                    //    tokenizer.filter(filter);
-                    //}
-                    manager.register(name, tokenizer)
-                }
+            let tokenizer: BoxTokenizer = match tokenizer_name {
+                "code" | "source_code" => self.code_tokenizer_factory.clone().create(),
+                "facet" => self.facet_tokenizer_factory.clone().create(),
+                "cang_jie" | "jieba" => self
+                    .jieba_tokenizer_factory
+                    .clone()
+                    .create(tokenizer_args.as_ref())
+                    .map_err(|reason| TokenizerConfigError::InvalidArgs {
+                        name: tokenizer_name.to_string(),
+                        reason,
+                    })?,
+                "ngram" => self
+                    .ngram_tokenizer_factory
+                    .clone()
+                    .create(tokenizer_args.as_ref())
+                    .map_err(|reason| TokenizerConfigError::InvalidArgs {
+                        name: tokenizer_name.to_string(),
+                        reason,
+                    })?,
+                "raw" => self.raw_tokenizer_factory.clone().create(),
+                "simple" => self.simple_tokenizer_factory.clone().create(),
                 _ => {
-                    panic!("unknown tokenizer: {}", tokenizer_name);
+                    return Err(TokenizerConfigError::UnknownTokenizer {
+                        name: tokenizer_name.to_string(),
+                    })
                 }
-            }
+            };
+
+            // when `detect_language` is set, wrap the base tokenizer so each
+            // call to `token_stream` dispatches to the matching per-language
+            // stemmer + stop-word pipeline instead of a single fixed one
+            let tokenizer = if tokenizer_config_map
+                .get("detect_language")
+                .and_then(Value::as_bool)
+                .unwrap_or(false)
+            {
+                let detect_language_args = serde_json::to_string(tokenizer_config_map).unwrap();
+                self.language_aware_tokenizer_factory
+                    .clone()
+                    .create(tokenizer, detect_language_args.as_ref())
+                    .map_err(|reason| TokenizerConfigError::InvalidArgs {
+                        name: "detect_language".to_string(),
+                        reason,
+                    })?
+            } else {
+                tokenizer
+            };
+
+            // fold the configured filters into the analyzer, in declared order
+            let analyzer = filters
+                .into_iter()
+                .fold(TextAnalyzer::builder(tokenizer), |builder, filter| {
+                    builder.filter_dynamic(filter)
+                })
+                .build();
+
+            manager.register(name, analyzer);
+            self.registered_config_hashes.insert(name.clone(), config_hash);
         }
 
         debug!("tokenizers are initialized");
+
+        Ok(())
     }
 }
 
@@ -187,6 +297,7 @@ mod tests {
     use crate::tokenizer::lower_case_filter_factory::LowerCaseFilterFactory;
     use crate::tokenizer::simple_tokenizer_factory::SimpleTokenizerFactory;
     use crate::tokenizer::stop_word_filter_factory::StopWordFilterFactory;
+    use crate::tokenizer::tokenizer_config_error::TokenizerConfigError;
     use crate::tokenizer::tokenizer_initializer::TokenizerInitializer;
 
     fn read_file(path: &str) -> String {
@@ -235,7 +346,7 @@ mod tests {
         let manager = TokenizerManager::default();
 
         let mut initializer = TokenizerInitializer::new();
-        initializer.init(&manager, config);
+        initializer.init(&manager, config).unwrap();
 
         let tokenizer = manager.get("en_text").unwrap();
         let mut stream = tokenizer.token_stream("HELLO world!");
@@ -253,4 +364,179 @@ mod tests {
         }
         assert!(stream.next().is_none());
     }
+
+    #[test]
+    fn test_unknown_filter() {
+        let config = r#"
+            {
+              "en_text": {
+                "tokenizer": { "name": "simple" },
+                "filters": [ { "name": "no_such_filter" } ]
+              }
+            }
+        "#;
+
+        let manager = TokenizerManager::default();
+        let mut initializer = TokenizerInitializer::new();
+        let err = initializer.init(&manager, config).unwrap_err();
+        assert!(matches!(
+            err,
+            TokenizerConfigError::UnknownFilter { name } if name == "no_such_filter"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_tokenizer() {
+        let config = r#"
+            {
+              "en_text": {
+                "tokenizer": { "name": "no_such_tokenizer" }
+              }
+            }
+        "#;
+
+        let manager = TokenizerManager::default();
+        let mut initializer = TokenizerInitializer::new();
+        let err = initializer.init(&manager, config).unwrap_err();
+        assert!(matches!(
+            err,
+            TokenizerConfigError::UnknownTokenizer { name } if name == "no_such_tokenizer"
+        ));
+    }
+
+    #[test]
+    fn test_missing_tokenizer_name() {
+        let config = r#"
+            {
+              "en_text": {
+                "tokenizer": { }
+              }
+            }
+        "#;
+
+        let manager = TokenizerManager::default();
+        let mut initializer = TokenizerInitializer::new();
+        let err = initializer.init(&manager, config).unwrap_err();
+        assert!(matches!(
+            err,
+            TokenizerConfigError::MissingField { path } if path == "/en_text/tokenizer/name"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_args_type() {
+        let config = r#"
+            {
+              "en_text": {
+                "tokenizer": { "name": "simple" },
+                "filters": [ { "name": "stop_word", "args": { "words": "not-an-array" } } ]
+              }
+            }
+        "#;
+
+        let manager = TokenizerManager::default();
+        let mut initializer = TokenizerInitializer::new();
+        let err = initializer.init(&manager, config).unwrap_err();
+        assert!(matches!(
+            err,
+            TokenizerConfigError::InvalidArgs { name, .. } if name == "stop_word"
+        ));
+    }
+
+    #[test]
+    fn test_config_hash_stable_across_key_order_and_sensitive_to_args() {
+        let config_a = r#"{"en_text": {"tokenizer": {"name": "simple"}, "filters": [{"name": "lower_case"}]}}"#;
+        let config_b = r#"{"en_text": {"filters": [{"name": "lower_case"}], "tokenizer": {"name": "simple"}}}"#;
+        let config_c = r#"{"en_text": {"tokenizer": {"name": "ngram"}, "filters": [{"name": "lower_case"}]}}"#;
+
+        let initializer = TokenizerInitializer::new();
+
+        assert_eq!(
+            initializer.config_hash("en_text", config_a).unwrap(),
+            initializer.config_hash("en_text", config_b).unwrap()
+        );
+        assert_ne!(
+            initializer.config_hash("en_text", config_a).unwrap(),
+            initializer.config_hash("en_text", config_c).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_config_hash_rejects_malformed_config_without_panicking() {
+        let initializer = TokenizerInitializer::new();
+
+        let err = initializer.config_hash("en_text", "not json").unwrap_err();
+        assert!(matches!(err, TokenizerConfigError::InvalidJson(_)));
+
+        let err = initializer
+            .config_hash("en_text", r#"{"en_text": "not an object"}"#)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TokenizerConfigError::MissingField { path } if path == "/en_text"
+        ));
+    }
+
+    #[test]
+    fn test_init_skips_unchanged_analyzer() {
+        let config = r#"
+            {
+              "en_text": {
+                "tokenizer": { "name": "simple" },
+                "filters": [ { "name": "lower_case" } ]
+              }
+            }
+        "#;
+
+        let manager = TokenizerManager::default();
+        let mut initializer = TokenizerInitializer::new();
+
+        initializer.init(&manager, config).unwrap();
+        let hash_after_first_init = *initializer.registered_config_hashes.get("en_text").unwrap();
+
+        // Swap in a stand-in analyzer without the lower-case filter. If `init`
+        // skips the unchanged config, the stand-in stays registered.
+        manager.register(
+            "en_text",
+            tantivy::tokenizer::TextAnalyzer::builder(SimpleTokenizerFactory::new().create())
+                .build(),
+        );
+
+        // Re-running `init` with the identical config should be a no-op:
+        // the tracked hash for "en_text" does not change and the stand-in
+        // analyzer is not replaced.
+        initializer.init(&manager, config).unwrap();
+        assert_eq!(
+            initializer.registered_config_hashes.get("en_text"),
+            Some(&hash_after_first_init)
+        );
+        let tokenizer = manager.get("en_text").unwrap();
+        let mut stream = tokenizer.token_stream("HELLO");
+        assert_eq!(stream.next().unwrap().text, "HELLO");
+    }
+
+    #[test]
+    fn test_init_registers_into_a_second_manager_with_unchanged_config() {
+        let config = r#"
+            {
+              "en_text": {
+                "tokenizer": { "name": "simple" },
+                "filters": [ { "name": "lower_case" } ]
+              }
+            }
+        "#;
+
+        let mut initializer = TokenizerInitializer::new();
+
+        let first_manager = TokenizerManager::default();
+        initializer.init(&first_manager, config).unwrap();
+        assert!(first_manager.get("en_text").is_some());
+
+        // Reusing the same initializer against a brand-new manager with the
+        // identical config must still register "en_text" there, even though
+        // the tracked hash already matches from the first manager.
+        let second_manager = TokenizerManager::default();
+        initializer.init(&second_manager, config).unwrap();
+        assert!(second_manager.get("en_text").is_some());
+    }
 }